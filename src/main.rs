@@ -1,9 +1,14 @@
 use std::{
+    cell::RefCell,
     collections::HashMap,
     fmt::{Debug, Formatter},
+    thread_local,
 };
 
-use cassowary::{strength::*, WeightedRelation::*, *};
+use cassowary::{
+    strength::*, Constraint as CassowaryConstraint, Expression, Solver, Variable,
+    WeightedRelation::*,
+};
 use color_eyre::{eyre::eyre, Result};
 use itertools::Itertools;
 
@@ -22,7 +27,7 @@ pub struct Element {
     height: Variable,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 pub struct Rect {
     x: f64,
     y: f64,
@@ -30,6 +35,196 @@ pub struct Rect {
     height: f64,
 }
 
+/// Padding to reserve around a group of elements before they are constrained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Margin {
+    pub horizontal: u16,
+    pub vertical: u16,
+}
+
+impl Rect {
+    /// Returns the rect remaining after insetting each side by `margin`, or an empty rect (at
+    /// this rect's origin) if the margin is larger than the available extent.
+    pub fn inner(&self, margin: Margin) -> Rect {
+        let doubled_horizontal = margin.horizontal as f64 * 2.0;
+        let doubled_vertical = margin.vertical as f64 * 2.0;
+        if doubled_horizontal > self.width || doubled_vertical > self.height {
+            Rect {
+                x: self.x,
+                y: self.y,
+                width: 0.0,
+                height: 0.0,
+            }
+        } else {
+            Rect {
+                x: self.x + margin.horizontal as f64,
+                y: self.y + margin.vertical as f64,
+                width: self.width - doubled_horizontal,
+                height: self.height - doubled_vertical,
+            }
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    pub fn left(&self) -> f64 {
+        self.x
+    }
+
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn top(&self) -> f64 {
+        self.y
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    /// The smallest rect that contains both `self` and `other`.
+    pub fn union(&self, other: &Rect) -> Rect {
+        let x = self.left().min(other.left());
+        let y = self.top().min(other.top());
+        let right = self.right().max(other.right());
+        let bottom = self.bottom().max(other.bottom());
+        Rect {
+            x,
+            y,
+            width: right - x,
+            height: bottom - y,
+        }
+    }
+
+    /// The overlapping region of `self` and `other`, or an empty rect when they don't overlap.
+    pub fn intersection(&self, other: &Rect) -> Rect {
+        let x = self.left().max(other.left());
+        let y = self.top().max(other.top());
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        Rect {
+            x,
+            y,
+            width: (right - x).max(0.0),
+            height: (bottom - y).max(0.0),
+        }
+    }
+
+    /// Whether the point `(x, y)` falls within this rect.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.left() && x < self.right() && y >= self.top() && y < self.bottom()
+    }
+}
+
+/// The direction in which elements are laid out end-to-end within an area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Horizontal,
+    Vertical,
+}
+
+/// A declarative sizing rule for one element of a [`Layout::split`] call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Constraint {
+    /// A fixed size, in cells.
+    Length(u16),
+    /// A percentage of the area's extent along the layout direction.
+    Percentage(u16),
+    /// A fraction (`numerator / denominator`) of the area's extent along the layout direction.
+    Ratio(u32, u32),
+    /// At least this many cells.
+    Min(u16),
+    /// At most this many cells.
+    Max(u16),
+}
+
+/// Configuration for [`Layout::split_with_config`] beyond the direction and constraints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayoutConfig {
+    pub margin: Margin,
+    /// Whether the last element's trailing edge is stretched to the area's trailing edge when
+    /// the constraints leave leftover space. Defaults to `true`. When this is `true`, leftover
+    /// space is always absorbed by the last element and `alignment` has no effect.
+    pub expand_to_fill: bool,
+    /// How leftover space is distributed along the layout direction when `expand_to_fill` is
+    /// `false` and the constrained elements don't fill the whole area. Defaults to `Start`.
+    pub alignment: Alignment,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        LayoutConfig {
+            margin: Margin::default(),
+            expand_to_fill: true,
+            alignment: Alignment::Start,
+        }
+    }
+}
+
+/// Where leftover space is placed relative to a group of elements along the layout direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Alignment {
+    /// Leftover space is placed after the group: the group is flush with the leading edge.
+    #[default]
+    Start,
+    /// Leftover space is split evenly before and after the group, centering it in the area.
+    Center,
+    /// Leftover space is placed before the group: the group is flush with the trailing edge.
+    End,
+}
+
+/// The inputs that fully determine the outcome of a [`Layout::split`] call, used as a cache key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct LayoutSpec {
+    direction: Direction,
+    margin: (u16, u16),
+    expand_to_fill: bool,
+    alignment: Alignment,
+    constraints: Vec<Constraint>,
+}
+
+/// An integer-quantized [`Rect`], used as a cache key since `f64` isn't `Hash`/`Eq`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct RectKey {
+    x: i64,
+    y: i64,
+    width: i64,
+    height: i64,
+}
+
+impl From<Rect> for RectKey {
+    fn from(rect: Rect) -> Self {
+        RectKey {
+            x: rect.x.round() as i64,
+            y: rect.y.round() as i64,
+            width: rect.width.round() as i64,
+            height: rect.height.round() as i64,
+        }
+    }
+}
+
+/// The inputs that fully determine the outcome of a [`Layout::columns`] call, used as a cache
+/// key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ColumnsSpec {
+    constraints: Vec<Constraint>,
+    column_spacing: u16,
+}
+
+thread_local! {
+    /// Memoizes `Layout::split` results so identical (area, direction, config, constraints)
+    /// inputs are a map lookup instead of a full simplex solve.
+    static LAYOUT_CACHE: RefCell<HashMap<(RectKey, LayoutSpec), Vec<Rect>>> =
+        RefCell::new(HashMap::new());
+
+    /// Memoizes `Layout::columns` results the same way `LAYOUT_CACHE` memoizes `Layout::split`.
+    static COLUMNS_CACHE: RefCell<HashMap<(RectKey, ColumnsSpec), Vec<Rect>>> =
+        RefCell::new(HashMap::new());
+}
+
 impl Layout {
     pub fn new(area: Rect) -> Result<Self> {
         let mut solver = Solver::new();
@@ -87,20 +282,20 @@ impl Layout {
         self.add_constraints(&[
             rect.left() | GE(REQUIRED) | self.area_element.left(),
             rect.top() | GE(REQUIRED) | self.area_element.top(),
-            rect.right() | LE(REQUIRED) | self.area_element.bottom(),
+            rect.right() | LE(REQUIRED) | self.area_element.right(),
             rect.bottom() | LE(REQUIRED) | self.area_element.bottom(),
         ])
     }
 
     /// adds multiple constraints to the solver
-    pub fn add_constraints(&mut self, constraints: &[Constraint]) -> Result<()> {
+    pub fn add_constraints(&mut self, constraints: &[CassowaryConstraint]) -> Result<()> {
         self.solver
             .add_constraints(constraints)
             .map_err(|e| eyre!("failed to add constraints: {e:?}"))
     }
 
     /// adds a single constraint to the solver
-    pub fn add_constraint(&mut self, constraint: Constraint) -> Result<()> {
+    pub fn add_constraint(&mut self, constraint: CassowaryConstraint) -> Result<()> {
         self.solver
             .add_constraint(constraint)
             .map_err(|e| eyre!("failed to add constraint: {e:?}"))
@@ -109,24 +304,280 @@ impl Layout {
     /// fetches the values of the variables from the solver and stores them in the layout
     /// returns the rects of the elements
     pub fn get_rects(&mut self) -> Vec<Rect> {
-        let mut rects = Vec::new();
         let changes = self.solver.fetch_changes();
         self.values.extend(changes.iter().copied());
-        for element in self.elements.iter() {
-            rects.push(Rect {
-                x: self.value(element.x),
-                y: self.value(element.y),
-                width: self.value(element.width),
-                height: self.value(element.height),
-            });
-        }
-        rects
+        // Round each edge (not each width/height) to the nearest integer cell. Adjacent elements
+        // share an exact floating-point edge value via their REQUIRED precedence constraint, so
+        // rounding that shared value once, rather than rounding each element's width/height
+        // independently, keeps the elements tiled without gaps or overlaps.
+        self.elements
+            .iter()
+            .map(|element| {
+                let left = self.value(element.x).round();
+                let top = self.value(element.y).round();
+                let right = (self.value(element.x) + self.value(element.width)).round();
+                let bottom = (self.value(element.y) + self.value(element.height)).round();
+                Rect {
+                    x: left,
+                    y: top,
+                    width: right - left,
+                    height: bottom - top,
+                }
+            })
+            .collect()
     }
 
     /// helper function to get the value of a variable from the solver
     fn value(&self, variable: Variable) -> f64 {
         self.values.get(&variable).copied().unwrap_or(0.0)
     }
+
+    /// Splits `area` into one [`Rect`] per constraint, laid out end-to-end along `direction`.
+    ///
+    /// `Length`/`Percentage`/`Ratio` constraints request an exact size at `STRONG` strength,
+    /// `Min`/`Max` bound it, and `REQUIRED` adjacency constraints tile the elements so they touch
+    /// without gaps and the first/last elements pin to the edges of `area`. When the requested
+    /// sizes don't add up to `area`'s extent, the `REQUIRED` edge pins win over the sizes.
+    pub fn split(
+        area: Rect,
+        direction: Direction,
+        constraints: &[Constraint],
+    ) -> Result<Vec<Rect>> {
+        Layout::split_with_config(area, direction, LayoutConfig::default(), constraints)
+    }
+
+    /// Like [`Layout::split`], but insets `area` by `margin` before constraining elements, so
+    /// the returned rects leave `margin` cells of padding around the group.
+    pub fn split_with_margin(
+        area: Rect,
+        direction: Direction,
+        margin: Margin,
+        constraints: &[Constraint],
+    ) -> Result<Vec<Rect>> {
+        let config = LayoutConfig {
+            margin,
+            ..LayoutConfig::default()
+        };
+        Layout::split_with_config(area, direction, config, constraints)
+    }
+
+    /// Like [`Layout::split`], but with full control over margin and trailing-space behavior via
+    /// `config`.
+    pub fn split_with_config(
+        area: Rect,
+        direction: Direction,
+        config: LayoutConfig,
+        constraints: &[Constraint],
+    ) -> Result<Vec<Rect>> {
+        let key = (
+            RectKey::from(area),
+            LayoutSpec {
+                direction,
+                margin: (config.margin.horizontal, config.margin.vertical),
+                expand_to_fill: config.expand_to_fill,
+                alignment: config.alignment,
+                constraints: constraints.to_vec(),
+            },
+        );
+        if let Some(rects) = LAYOUT_CACHE.with(|cache| cache.borrow().get(&key).cloned()) {
+            return Ok(rects);
+        }
+
+        let area = area.inner(config.margin);
+        let mut layout = Layout::new(area)?;
+        let elements: Vec<Element> = constraints.iter().map(|_| Element::new()).collect();
+        for element in &elements {
+            layout.add_element(*element)?;
+        }
+
+        let area_extent = match direction {
+            Direction::Horizontal => area.width,
+            Direction::Vertical => area.height,
+        };
+
+        for (element, constraint) in elements.iter().zip(constraints) {
+            let size_constraint = match direction {
+                Direction::Horizontal => match *constraint {
+                    Constraint::Length(length) => element.has_width(length as f64),
+                    Constraint::Percentage(percentage) => {
+                        element.has_width(area_extent * percentage as f64 / 100.0)
+                    }
+                    Constraint::Ratio(numerator, denominator) => {
+                        element.has_width(area_extent * numerator as f64 / denominator as f64)
+                    }
+                    Constraint::Min(min) => element.has_minimum_width(min as f64),
+                    Constraint::Max(max) => element.has_maximum_width(max as f64),
+                },
+                Direction::Vertical => match *constraint {
+                    Constraint::Length(length) => element.has_height(length as f64),
+                    Constraint::Percentage(percentage) => {
+                        element.has_height(area_extent * percentage as f64 / 100.0)
+                    }
+                    Constraint::Ratio(numerator, denominator) => {
+                        element.has_height(area_extent * numerator as f64 / denominator as f64)
+                    }
+                    Constraint::Min(min) => element.has_minimum_height(min as f64),
+                    Constraint::Max(max) => element.has_maximum_height(max as f64),
+                },
+            };
+            layout.add_constraint(size_constraint)?;
+        }
+
+        match direction {
+            Direction::Horizontal => {
+                for (&left, &right) in elements.iter().tuple_windows() {
+                    layout.add_constraint(left.precedes_horizontally(right))?;
+                }
+            }
+            Direction::Vertical => {
+                for (&left, &right) in elements.iter().tuple_windows() {
+                    layout.add_constraint(left.precedes_vertically(right))?;
+                }
+            }
+        }
+
+        if let (Some(&first), Some(&last)) = (elements.first(), elements.last()) {
+            if config.expand_to_fill {
+                // The last element absorbs all leftover space, so the group always starts at the
+                // leading edge and ends at the trailing edge; alignment has nothing to do.
+                match direction {
+                    Direction::Horizontal => layout.add_constraints(&[
+                        first.left() | EQ(REQUIRED) | layout.area_element.left(),
+                        last.right() | EQ(REQUIRED) | layout.area_element.right(),
+                    ])?,
+                    Direction::Vertical => layout.add_constraints(&[
+                        first.top() | EQ(REQUIRED) | layout.area_element.top(),
+                        last.bottom() | EQ(REQUIRED) | layout.area_element.bottom(),
+                    ])?,
+                }
+            } else {
+                match config.alignment {
+                    Alignment::Start => match direction {
+                        Direction::Horizontal => layout.add_constraint(
+                            first.left() | EQ(REQUIRED) | layout.area_element.left(),
+                        )?,
+                        Direction::Vertical => layout.add_constraint(
+                            first.top() | EQ(REQUIRED) | layout.area_element.top(),
+                        )?,
+                    },
+                    Alignment::End => match direction {
+                        Direction::Horizontal => layout.add_constraint(
+                            last.right() | EQ(REQUIRED) | layout.area_element.right(),
+                        )?,
+                        Direction::Vertical => layout.add_constraint(
+                            last.bottom() | EQ(REQUIRED) | layout.area_element.bottom(),
+                        )?,
+                    },
+                    Alignment::Center => match direction {
+                        Direction::Horizontal => layout.add_constraint(
+                            ((first.left() + last.right()) / 2.0)
+                                | EQ(WEAK)
+                                | ((layout.area_element.left() + layout.area_element.right())
+                                    / 2.0),
+                        )?,
+                        Direction::Vertical => layout.add_constraint(
+                            ((first.top() + last.bottom()) / 2.0)
+                                | EQ(WEAK)
+                                | ((layout.area_element.top() + layout.area_element.bottom())
+                                    / 2.0),
+                        )?,
+                    },
+                }
+            }
+        }
+
+        let rects = layout.get_rects();
+        LAYOUT_CACHE.with(|cache| cache.borrow_mut().insert(key, rects.clone()));
+        Ok(rects)
+    }
+
+    /// Clears the memoized results of previous [`Layout::split`] and [`Layout::columns`] calls.
+    pub fn clear_cache() {
+        LAYOUT_CACHE.with(|cache| cache.borrow_mut().clear());
+        COLUMNS_CACHE.with(|cache| cache.borrow_mut().clear());
+    }
+
+    /// Lays out `constraints.len()` columns horizontally across `area`, separated by
+    /// `column_spacing` cells of fixed gap, and returns each column's inner content rect
+    /// (excluding the spacing gaps).
+    ///
+    /// `Length`/`Percentage`/`Ratio` columns get their requested width at `STRONG`, `Min`/`Max`
+    /// columns are bounded with `GE`/`LE`, and any unconstrained residual is split evenly among
+    /// the flexible (`Min`/`Max`) columns via a `WEAK` constraint tying their widths together.
+    /// Results are memoized the same way [`Layout::split`] results are, since table column
+    /// layouts are typically re-requested with identical inputs on every frame.
+    pub fn columns(
+        area: Rect,
+        constraints: &[Constraint],
+        column_spacing: u16,
+    ) -> Result<Vec<Rect>> {
+        let key = (
+            RectKey::from(area),
+            ColumnsSpec {
+                constraints: constraints.to_vec(),
+                column_spacing,
+            },
+        );
+        if let Some(rects) = COLUMNS_CACHE.with(|cache| cache.borrow().get(&key).cloned()) {
+            return Ok(rects);
+        }
+
+        let mut layout = Layout::new(area)?;
+        let columns: Vec<Element> = constraints.iter().map(|_| Element::new()).collect();
+        for column in &columns {
+            layout.add_element(*column)?;
+        }
+
+        for (column, constraint) in columns.iter().zip(constraints) {
+            let size_constraint = match *constraint {
+                Constraint::Length(length) => column.has_width(length as f64),
+                Constraint::Percentage(percentage) => {
+                    column.has_width(area.width * percentage as f64 / 100.0)
+                }
+                Constraint::Ratio(numerator, denominator) => {
+                    column.has_width(area.width * numerator as f64 / denominator as f64)
+                }
+                Constraint::Min(min) => column.has_minimum_width(min as f64),
+                Constraint::Max(max) => column.has_maximum_width(max as f64),
+            };
+            layout.add_constraint(size_constraint)?;
+        }
+
+        // Columns with Min/Max constraints are flexible; tie their widths together so any
+        // leftover space (after fixed-size columns are subtracted) is split evenly between them.
+        let flexible: Vec<Element> = columns
+            .iter()
+            .zip(constraints)
+            .filter(|(_, constraint)| matches!(constraint, Constraint::Min(_) | Constraint::Max(_)))
+            .map(|(column, _)| *column)
+            .collect();
+        for (&left, &right) in flexible.iter().tuple_windows() {
+            layout.add_constraint(left.width | EQ(WEAK) | right.width)?;
+        }
+
+        for (&left, &right) in columns.iter().tuple_windows() {
+            layout.add_constraint(
+                (left.right() + column_spacing as f64) | EQ(REQUIRED) | right.left(),
+            )?;
+        }
+
+        if let Some(&first) = columns.first() {
+            layout.add_constraint(first.left() | EQ(REQUIRED) | layout.area_element.left())?;
+        }
+        // Only pin the trailing edge to the area's far edge when a flexible column exists
+        // somewhere to absorb the slack; otherwise this REQUIRED pin would override a
+        // fixed-size column's own STRONG width constraint, silently stretching it past its
+        // requested size.
+        if !flexible.is_empty() {
+            if let Some(&last) = columns.last() {
+                layout.add_constraint(last.right() | EQ(REQUIRED) | layout.area_element.right())?;
+            }
+        }
+
+        let rects = layout.get_rects();
+        COLUMNS_CACHE.with(|cache| cache.borrow_mut().insert(key, rects.clone()));
+        Ok(rects)
+    }
 }
 
 impl Element {
@@ -156,43 +607,43 @@ impl Element {
         self.y + self.height
     }
 
-    pub fn precedes_horizontally(&self, other: Element) -> Constraint {
+    pub fn precedes_horizontally(&self, other: Element) -> CassowaryConstraint {
         self.right() | EQ(REQUIRED) | other.left()
     }
 
-    pub fn precedes_vertically(&self, other: Element) -> Constraint {
+    pub fn precedes_vertically(&self, other: Element) -> CassowaryConstraint {
         self.bottom() | EQ(REQUIRED) | other.top()
     }
 
-    pub fn has_width(&self, width: f64) -> Constraint {
+    pub fn has_width(&self, width: f64) -> CassowaryConstraint {
         self.width | EQ(STRONG) | width
     }
 
-    pub fn has_minimum_width(&self, width: f64) -> Constraint {
+    pub fn has_minimum_width(&self, width: f64) -> CassowaryConstraint {
         self.width | GE(STRONG) | width
     }
 
-    pub fn has_maximum_width(&self, width: f64) -> Constraint {
+    pub fn has_maximum_width(&self, width: f64) -> CassowaryConstraint {
         self.width | LE(STRONG) | width
     }
 
-    pub fn has_proportional_width(&self, other: Element, ratio: f64) -> Constraint {
+    pub fn has_proportional_width(&self, other: Element, ratio: f64) -> CassowaryConstraint {
         (self.width / ratio) | EQ(MEDIUM) | other.width
     }
 
-    pub fn has_height(&self, height: f64) -> Constraint {
+    pub fn has_height(&self, height: f64) -> CassowaryConstraint {
         self.height | EQ(STRONG) | height
     }
 
-    pub fn has_minimum_height(&self, height: f64) -> Constraint {
+    pub fn has_minimum_height(&self, height: f64) -> CassowaryConstraint {
         self.height | GE(STRONG) | height
     }
 
-    pub fn has_maximum_height(&self, height: f64) -> Constraint {
+    pub fn has_maximum_height(&self, height: f64) -> CassowaryConstraint {
         self.height | LE(STRONG) | height
     }
 
-    pub fn has_proportional_height(&self, other: Element, ratio: f64) -> Constraint {
+    pub fn has_proportional_height(&self, other: Element, ratio: f64) -> CassowaryConstraint {
         (self.height / ratio) | EQ(MEDIUM) | other.height
     }
 }
@@ -256,3 +707,223 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f64, y: f64, width: f64, height: f64) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    // Regression test for a bug where `Layout::add_element` compared an element's horizontal
+    // right edge against the area's *vertical* bottom edge, which silently dragged the solved
+    // area's width/height away from the caller's requested `Rect` on any non-square area.
+    #[test]
+    fn split_preserves_non_primary_extent_on_non_square_area() {
+        let area = rect(0.0, 0.0, 80.0, 24.0);
+        let rects = Layout::split(
+            area,
+            Direction::Horizontal,
+            &[Constraint::Length(40), Constraint::Length(40)],
+        )
+        .unwrap();
+
+        assert_eq!(rects[0], rect(0.0, 0.0, 40.0, 24.0));
+        assert_eq!(rects[1], rect(40.0, 0.0, 40.0, 24.0));
+    }
+
+    #[test]
+    fn split_with_config_preserves_non_primary_extent_on_non_square_area() {
+        let area = rect(0.0, 0.0, 80.0, 24.0);
+        let rects = Layout::split_with_config(
+            area,
+            Direction::Vertical,
+            LayoutConfig::default(),
+            &[Constraint::Length(12), Constraint::Length(12)],
+        )
+        .unwrap();
+
+        assert_eq!(rects[0], rect(0.0, 0.0, 80.0, 12.0));
+        assert_eq!(rects[1], rect(0.0, 12.0, 80.0, 12.0));
+    }
+
+    #[test]
+    fn columns_preserves_non_primary_extent_on_non_square_area() {
+        let area = rect(0.0, 0.0, 80.0, 24.0);
+        let rects = Layout::columns(
+            area,
+            &[Constraint::Length(20), Constraint::Length(20), Constraint::Min(0)],
+            1,
+        )
+        .unwrap();
+
+        assert_eq!(rects[0], rect(0.0, 0.0, 20.0, 24.0));
+        assert_eq!(rects[1], rect(21.0, 0.0, 20.0, 24.0));
+        assert_eq!(rects[2], rect(42.0, 0.0, 38.0, 24.0));
+    }
+
+    // Without a `Min`/`Max` flexible column to absorb the slack, fixed-size columns must keep
+    // their exact requested widths rather than being silently stretched to fill the area.
+    #[test]
+    fn columns_keeps_fixed_sizes_when_no_flexible_column_is_present() {
+        let area = rect(0.0, 0.0, 100.0, 100.0);
+        let rects = Layout::columns(
+            area,
+            &[Constraint::Length(20), Constraint::Length(20)],
+            1,
+        )
+        .unwrap();
+
+        assert_eq!(rects[0], rect(0.0, 0.0, 20.0, 100.0));
+        assert_eq!(rects[1], rect(21.0, 0.0, 20.0, 100.0));
+    }
+
+    #[test]
+    fn union_covers_both_rects() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(5.0, 5.0, 10.0, 10.0);
+        assert_eq!(a.union(&b), rect(0.0, 0.0, 15.0, 15.0));
+    }
+
+    #[test]
+    fn intersection_of_overlapping_rects() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(5.0, 5.0, 10.0, 10.0);
+        assert_eq!(a.intersection(&b), rect(5.0, 5.0, 5.0, 5.0));
+    }
+
+    #[test]
+    fn intersection_of_disjoint_rects_is_zero_area_not_negative() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(20.0, 20.0, 10.0, 10.0);
+        let intersection = a.intersection(&b);
+        assert_eq!(intersection.width, 0.0);
+        assert_eq!(intersection.height, 0.0);
+    }
+
+    #[test]
+    fn intersection_of_touching_edge_rects_is_zero_area() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(10.0, 0.0, 10.0, 10.0);
+        let intersection = a.intersection(&b);
+        assert_eq!(intersection.width, 0.0);
+        assert_eq!(intersection.height, 10.0);
+    }
+
+    // Bounds are half-open: a point exactly on the right or bottom edge belongs to the
+    // neighboring rect, not this one.
+    #[test]
+    fn contains_excludes_right_and_bottom_edges() {
+        let area = rect(0.0, 0.0, 10.0, 10.0);
+        assert!(area.contains(0.0, 0.0));
+        assert!(area.contains(9.9, 9.9));
+        assert!(!area.contains(area.right(), 5.0));
+        assert!(!area.contains(5.0, area.bottom()));
+    }
+
+    #[test]
+    fn split_populates_and_reuses_the_layout_cache() {
+        Layout::clear_cache();
+        let area = rect(0.0, 0.0, 40.0, 10.0);
+        let constraints = [Constraint::Length(20), Constraint::Length(20)];
+
+        assert_eq!(LAYOUT_CACHE.with(|cache| cache.borrow().len()), 0);
+        let first = Layout::split(area, Direction::Horizontal, &constraints).unwrap();
+        assert_eq!(LAYOUT_CACHE.with(|cache| cache.borrow().len()), 1);
+
+        // An identical second call is a cache hit, not a second solve, so it returns the same
+        // rects without growing the cache.
+        let second = Layout::split(area, Direction::Horizontal, &constraints).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(LAYOUT_CACHE.with(|cache| cache.borrow().len()), 1);
+    }
+
+    #[test]
+    fn clear_cache_forces_a_fresh_solve() {
+        Layout::clear_cache();
+        let area = rect(0.0, 0.0, 40.0, 10.0);
+        Layout::split(
+            area,
+            Direction::Horizontal,
+            &[Constraint::Length(20), Constraint::Length(20)],
+        )
+        .unwrap();
+        assert_eq!(LAYOUT_CACHE.with(|cache| cache.borrow().len()), 1);
+
+        Layout::clear_cache();
+        assert_eq!(LAYOUT_CACHE.with(|cache| cache.borrow().len()), 0);
+
+        // A different constraint set under the same area must produce its own, correct result
+        // rather than reusing anything left over from before the clear.
+        let rects = Layout::split(
+            area,
+            Direction::Horizontal,
+            &[Constraint::Length(10), Constraint::Length(30)],
+        )
+        .unwrap();
+        assert_eq!(rects[0], rect(0.0, 0.0, 10.0, 10.0));
+        assert_eq!(rects[1], rect(10.0, 0.0, 30.0, 10.0));
+    }
+
+    #[test]
+    fn columns_populates_and_reuses_the_columns_cache() {
+        Layout::clear_cache();
+        let area = rect(0.0, 0.0, 41.0, 10.0);
+        let constraints = [Constraint::Length(20), Constraint::Length(20)];
+
+        assert_eq!(COLUMNS_CACHE.with(|cache| cache.borrow().len()), 0);
+        let first = Layout::columns(area, &constraints, 1).unwrap();
+        assert_eq!(COLUMNS_CACHE.with(|cache| cache.borrow().len()), 1);
+
+        let second = Layout::columns(area, &constraints, 1).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(COLUMNS_CACHE.with(|cache| cache.borrow().len()), 1);
+
+        Layout::clear_cache();
+        assert_eq!(COLUMNS_CACHE.with(|cache| cache.borrow().len()), 0);
+    }
+
+    #[test]
+    fn inner_insets_rect_by_margin() {
+        let area = rect(0.0, 0.0, 20.0, 10.0);
+        let margin = Margin {
+            horizontal: 2,
+            vertical: 1,
+        };
+        assert_eq!(area.inner(margin), rect(2.0, 1.0, 16.0, 8.0));
+    }
+
+    #[test]
+    fn inner_clamps_to_empty_rect_when_margin_exceeds_extent() {
+        let area = rect(5.0, 5.0, 10.0, 4.0);
+        // doubled vertical margin (6) exceeds the area's height (4).
+        let margin = Margin {
+            horizontal: 2,
+            vertical: 3,
+        };
+        assert_eq!(area.inner(margin), rect(5.0, 5.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn split_with_margin_clamps_when_margin_exceeds_area() {
+        let area = rect(0.0, 0.0, 10.0, 4.0);
+        let rects = Layout::split_with_margin(
+            area,
+            Direction::Horizontal,
+            Margin {
+                horizontal: 2,
+                vertical: 3,
+            },
+            &[Constraint::Length(5)],
+        )
+        .unwrap();
+
+        assert_eq!(rects[0], rect(0.0, 0.0, 0.0, 0.0));
+    }
+}